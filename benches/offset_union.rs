@@ -0,0 +1,60 @@
+//! Benchmarks the aggregate-offset union restructuring: unioning every polygon into the
+//! accumulator in a single Clipper `Execute` call (`union_all`) versus the old O(n²) approach
+//! of folding `.union(..)` over the polygons one at a time, re-scaling/re-integerizing the
+//! accumulator on every iteration.
+//!
+//! There is no `Cargo.toml`/`[[bench]]` entry wiring this into a runnable harness in this
+//! snapshot of the crate; it documents the comparison `union_all` (see `src/offset.rs`) was
+//! written to win.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use geo_clipper::Clipper;
+use geo_offset::{ArcResolution, EndCap, JoinType, Offset};
+use geo_types::{Coord, LineString, MultiPolygon, Polygon};
+
+/// A ring of `n` evenly spaced points around a circle of radius `10`, each offset by `0.1`. Each
+/// point's own offset buffer is a small polygon, so unioning `n` of them together exercises
+/// the same aggregation path as offsetting a `MultiPoint`/`MultiLineString` with `n` elements.
+fn sample_offsets(n: usize) -> Vec<Polygon<f64>> {
+    (0..n)
+        .map(|i| {
+            let angle = i as f64 / n as f64 * std::f64::consts::TAU;
+            let center = Coord {
+                x: angle.cos() * 10.0,
+                y: angle.sin() * 10.0,
+            };
+            LineString(vec![center])
+                .offset_with_options(0.1, ArcResolution::default(), JoinType::Round, EndCap::Round)
+                .unwrap()
+                .0
+                .remove(0)
+        })
+        .collect()
+}
+
+/// Folds `.union(..)` over the polygons one at a time, the way aggregate offsets used to
+/// before the batched `union_all` rewrite.
+fn union_pairwise(polygons: &[Polygon<f64>]) -> MultiPolygon<f64> {
+    polygons.iter().fold(MultiPolygon(Vec::new()), |acc, polygon| {
+        acc.union(polygon, 1000.0)
+    })
+}
+
+fn bench_union(c: &mut Criterion) {
+    let mut group = c.benchmark_group("offset_union");
+    for &n in &[10usize, 50, 100, 200] {
+        let polygons = sample_offsets(n);
+        let subject = MultiPolygon(polygons);
+
+        group.bench_function(format!("pairwise/{n}"), |b| {
+            b.iter(|| union_pairwise(black_box(&subject.0)))
+        });
+        group.bench_function(format!("batched/{n}"), |b| {
+            b.iter(|| subject.union(black_box(&MultiPolygon(Vec::new())), 1000.0))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_union);
+criterion_main!(benches);
@@ -8,6 +8,9 @@ use num_traits::FloatConst;
 pub enum OffsetError {
     /// This error can be produced when manipulating edges.
     EdgeError(EdgeError),
+    /// Returned by `offset_variable` when the number of distances doesn't match the number
+    /// of vertices it is supposed to apply to.
+    DistanceCountMismatch,
 }
 
 /// `geo-clipper` does integer computation and requires a factor to enlarge the shapes
@@ -24,6 +27,17 @@ impl<F: CoordFloat> ClipperFactor for F {
     }
 }
 
+/// Dissolves every polygon in `polygons` into the others in a single Clipper `Execute`
+/// call. Aggregate offsets used to fold `.union(..)` over their elements one at a time,
+/// which is O(n²) in Clipper invocations and re-scales/re-integerizes the accumulator on
+/// every iteration; collecting everything up front and unioning it against an empty
+/// `MultiPolygon` lets Clipper union the whole subject set at once instead.
+fn union_all<F: CoordFloat + FloatConst>(
+    polygons: Vec<geo_types::Polygon<F>>,
+) -> geo_types::MultiPolygon<F> {
+    geo_types::MultiPolygon(polygons).union(&geo_types::MultiPolygon(Vec::new()), F::clipper_factor())
+}
+
 /// Resolution of arcs generated around corners for positive offsets.
 ///
 /// ```
@@ -38,6 +52,10 @@ pub enum ArcResolution<F: CoordFloat + FloatConst> {
     SegmentCount(usize),
     /// Sets the desired segment length, so that the number of segments is chosen based on the length of the arc.
     SegmentLength(F),
+    /// Sets the maximum allowed deviation between the true arc and its polyline approximation
+    /// (the sagitta of each segment), so that the number of segments is chosen based on the
+    /// radius and span of the arc.
+    MaxDeviation(F),
 }
 
 impl<F: CoordFloat + FloatConst> Default for ArcResolution<F> {
@@ -46,101 +64,205 @@ impl<F: CoordFloat + FloatConst> Default for ArcResolution<F> {
     }
 }
 
+/// Style used to join two offset edges at a convex corner.
+///
+/// ```
+/// # use geo_offset::JoinType;
+/// // The default join is round, matching the historical behaviour of this crate.
+/// let join: JoinType<f32> = Default::default();
+/// assert_eq!(join, JoinType::Round);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum JoinType<F: CoordFloat + FloatConst> {
+    /// Connects the two edges with an arc, as `create_arc` always did historically.
+    Round,
+    /// Connects the two edges with a single straight segment.
+    Bevel,
+    /// Extends the two edges until they meet, as long as the resulting miter length
+    /// (the distance between the original vertex and the intersection, divided by the
+    /// offset distance) does not exceed the given limit. Falls back to `Bevel` otherwise.
+    Miter(F),
+    /// Connects the two edges with a flat, chamfered corner extended by the offset distance,
+    /// mirroring Clipper's square join.
+    Square,
+}
+
+impl<F: CoordFloat + FloatConst> Default for JoinType<F> {
+    fn default() -> Self {
+        Self::Round
+    }
+}
+
+/// Style used to terminate the true endpoints of an open `LineString` or `MultiLineString`.
+///
+/// Interior joints between segments are unaffected by this setting; they are still
+/// controlled by `JoinType`. Only the first vertex of the first line and the last vertex
+/// of the last line are terminated using `EndCap`.
+///
+/// ```
+/// # use geo_offset::EndCap;
+/// // The default end cap is round, matching the historical behaviour of this crate.
+/// let end_cap: EndCap<f32> = Default::default();
+/// assert_eq!(end_cap, EndCap::Round);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum EndCap<F: CoordFloat + FloatConst> {
+    /// Terminates the line with a semicircular arc, as this crate always did historically.
+    Round,
+    /// Terminates the line with a flat edge, flush with the endpoint.
+    Butt,
+    /// Terminates the line with a flat edge, extended past the endpoint by the offset distance.
+    Square,
+}
+
+impl<F: CoordFloat + FloatConst> Default for EndCap<F> {
+    fn default() -> Self {
+        Self::Round
+    }
+}
+
+/// `EndCap` is a restriction of `JoinType`: a cap has no equivalent to `Miter`, since there
+/// is no second edge for it to intersect with. The remaining three styles reuse the identical
+/// corner construction as their `JoinType` counterparts.
+impl<F: CoordFloat + FloatConst> From<EndCap<F>> for JoinType<F> {
+    fn from(end_cap: EndCap<F>) -> Self {
+        match end_cap {
+            EndCap::Round => JoinType::Round,
+            EndCap::Butt => JoinType::Bevel,
+            EndCap::Square => JoinType::Square,
+        }
+    }
+}
+
 pub trait Offset<F: CoordFloat + FloatConst> {
     fn offset(&self, distance: F) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
-        self.offset_with_arc_resolution(distance, Default::default())
+        self.offset_with_options(
+            distance,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )
     }
 
     fn offset_with_arc_resolution(
         &self,
         distance: F,
         arc_resolution: ArcResolution<F>,
+    ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
+        self.offset_with_options(
+            distance,
+            arc_resolution,
+            Default::default(),
+            Default::default(),
+        )
+    }
+
+    fn offset_with_options(
+        &self,
+        distance: F,
+        arc_resolution: ArcResolution<F>,
+        join_type: JoinType<F>,
+        end_cap: EndCap<F>,
     ) -> Result<geo_types::MultiPolygon<F>, OffsetError>;
 }
 
 impl<F: CoordFloat + FloatConst> Offset<F> for geo_types::GeometryCollection<F> {
-    fn offset_with_arc_resolution(
+    fn offset_with_options(
         &self,
         distance: F,
         arc_resolution: ArcResolution<F>,
+        join_type: JoinType<F>,
+        end_cap: EndCap<F>,
     ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
-        let mut geometry_collection_with_offset = geo_types::MultiPolygon::<F>(Vec::new());
+        let mut polygons = Vec::new();
         for geometry in self.0.iter() {
-            let geometry_with_offset = geometry.offset_with_arc_resolution(distance, arc_resolution)?;
-            geometry_collection_with_offset = geometry_collection_with_offset
-                .union(&geometry_with_offset, F::clipper_factor());
+            let geometry_with_offset =
+                geometry.offset_with_options(distance, arc_resolution, join_type, end_cap)?;
+            polygons.extend(geometry_with_offset.0);
         }
-        Ok(geometry_collection_with_offset)
+        Ok(union_all(polygons))
     }
 }
 
 impl<F: CoordFloat + FloatConst> Offset<F> for geo_types::Geometry<F> {
-    fn offset_with_arc_resolution(
+    fn offset_with_options(
         &self,
         distance: F,
         arc_resolution: ArcResolution<F>,
+        join_type: JoinType<F>,
+        end_cap: EndCap<F>,
     ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
         match self {
             geo_types::Geometry::Point(point) => {
-                point.offset_with_arc_resolution(distance, arc_resolution)
+                point.offset_with_options(distance, arc_resolution, join_type, end_cap)
             }
             geo_types::Geometry::Line(line) => {
-                line.offset_with_arc_resolution(distance, arc_resolution)
+                line.offset_with_options(distance, arc_resolution, join_type, end_cap)
             }
             geo_types::Geometry::LineString(line_tring) => {
-                line_tring.offset_with_arc_resolution(distance, arc_resolution)
+                line_tring.offset_with_options(distance, arc_resolution, join_type, end_cap)
             }
             geo_types::Geometry::Triangle(triangle) => triangle
                 .to_polygon()
-                .offset_with_arc_resolution(distance, arc_resolution),
+                .offset_with_options(distance, arc_resolution, join_type, end_cap),
             geo_types::Geometry::Rect(rect) => rect
                 .to_polygon()
-                .offset_with_arc_resolution(distance, arc_resolution),
+                .offset_with_options(distance, arc_resolution, join_type, end_cap),
             geo_types::Geometry::Polygon(polygon) => {
-                polygon.offset_with_arc_resolution(distance, arc_resolution)
+                polygon.offset_with_options(distance, arc_resolution, join_type, end_cap)
             }
             geo_types::Geometry::MultiPoint(multi_point) => {
-                multi_point.offset_with_arc_resolution(distance, arc_resolution)
+                multi_point.offset_with_options(distance, arc_resolution, join_type, end_cap)
             }
             geo_types::Geometry::MultiLineString(multi_line_string) => {
-                multi_line_string.offset_with_arc_resolution(distance, arc_resolution)
+                multi_line_string.offset_with_options(distance, arc_resolution, join_type, end_cap)
             }
             geo_types::Geometry::MultiPolygon(multi_polygon) => {
-                multi_polygon.offset_with_arc_resolution(distance, arc_resolution)
+                multi_polygon.offset_with_options(distance, arc_resolution, join_type, end_cap)
             }
             geo_types::Geometry::GeometryCollection(geometry_collection) => {
-                geometry_collection.offset_with_arc_resolution(distance, arc_resolution)
+                geometry_collection.offset_with_options(distance, arc_resolution, join_type, end_cap)
             }
         }
     }
 }
 
 impl<F: CoordFloat + FloatConst> Offset<F> for geo_types::MultiPolygon<F> {
-    fn offset_with_arc_resolution(
+    fn offset_with_options(
         &self,
         distance: F,
         arc_resolution: ArcResolution<F>,
+        join_type: JoinType<F>,
+        end_cap: EndCap<F>,
     ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
-        let mut polygons = geo_types::MultiPolygon::<F>(Vec::new());
+        let mut polygons = Vec::new();
         for polygon in self.0.iter() {
-            let polygon_with_offset = polygon.offset_with_arc_resolution(distance, arc_resolution)?;
-            polygons = polygons.union(&polygon_with_offset, F::clipper_factor());
+            let polygon_with_offset =
+                polygon.offset_with_options(distance, arc_resolution, join_type, end_cap)?;
+            polygons.extend(polygon_with_offset.0);
         }
-        Ok(polygons)
+        Ok(union_all(polygons))
     }
 }
 
 impl<F: CoordFloat + FloatConst> Offset<F> for geo_types::Polygon<F> {
-    fn offset_with_arc_resolution(
+    fn offset_with_options(
         &self,
         distance: F,
         arc_resolution: ArcResolution<F>,
+        join_type: JoinType<F>,
+        end_cap: EndCap<F>,
     ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
-        let exterior_with_offset = self
-            .exterior()
-            .offset_with_arc_resolution(distance.abs(), arc_resolution)?;
+        // Rings are always closed, so the end-cap concept doesn't apply to them: every
+        // vertex is an interior joint, handled by `join_type` alone.
+        let exterior_with_offset = self.exterior().offset_with_options(
+            distance.abs(),
+            arc_resolution,
+            join_type,
+            EndCap::Round,
+        )?;
         let interiors_with_offset = geo_types::MultiLineString::<F>(self.interiors().to_vec())
-            .offset_with_arc_resolution(distance.abs(), arc_resolution)?;
+            .offset_with_options(distance.abs(), arc_resolution, join_type, EndCap::Round)?;
 
         Ok(if distance.is_sign_positive() {
             self.union(&exterior_with_offset, F::clipper_factor())
@@ -153,130 +275,296 @@ impl<F: CoordFloat + FloatConst> Offset<F> for geo_types::Polygon<F> {
 }
 
 impl<F: CoordFloat + FloatConst> Offset<F> for geo_types::MultiLineString<F> {
-    fn offset_with_arc_resolution(
+    fn offset_with_options(
         &self,
         distance: F,
         arc_resolution: ArcResolution<F>,
+        join_type: JoinType<F>,
+        end_cap: EndCap<F>,
     ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
         if distance < F::zero() {
             return Ok(geo_types::MultiPolygon(Vec::new()));
         }
 
-        let mut multi_line_string_with_offset = geo_types::MultiPolygon::<F>(Vec::new());
+        let mut polygons = Vec::new();
         for line_string in self.0.iter() {
             let line_string_with_offset =
-                line_string.offset_with_arc_resolution(distance, arc_resolution)?;
-            multi_line_string_with_offset = multi_line_string_with_offset
-                .union(&line_string_with_offset, F::clipper_factor());
+                line_string.offset_with_options(distance, arc_resolution, join_type, end_cap)?;
+            polygons.extend(line_string_with_offset.0);
         }
-        Ok(multi_line_string_with_offset)
+        Ok(union_all(polygons))
     }
 }
 
 impl<F: CoordFloat + FloatConst> Offset<F> for geo_types::LineString<F> {
-    fn offset_with_arc_resolution(
+    fn offset_with_options(
         &self,
         distance: F,
         arc_resolution: ArcResolution<F>,
+        join_type: JoinType<F>,
+        end_cap: EndCap<F>,
     ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
-        if distance < F::zero() {
-            return Ok(geo_types::MultiPolygon(Vec::new()));
-        }
-
-        let mut line_string_with_offset = geo_types::MultiPolygon::<F>(Vec::new());
-        for line in self.lines() {
-            let line_with_offset = line.offset_with_arc_resolution(distance, arc_resolution)?;
-            line_string_with_offset =
-                line_string_with_offset.union(&line_with_offset, F::clipper_factor());
-        }
-
-        let line_string_with_offset = line_string_with_offset.0.iter().skip(1).fold(
-            geo_types::MultiPolygon::<F>(
-                line_string_with_offset
-                    .0
-                    .get(0)
-                    .map(|polygon| vec![polygon.clone()])
-                    .unwrap_or_default(),
-            ),
-            |result, hole| result.difference(hole, F::clipper_factor()),
-        );
-
-        Ok(line_string_with_offset)
+        // A line string is a closed ring when its first and last coordinates coincide; its
+        // single wraparound joint is then handled like any other interior joint. An open
+        // line's two true endpoints use `end_cap` instead of `join_type`.
+        let closed = self.0.first() == self.0.last();
+        offset_path(
+            &self.0,
+            closed,
+            distance,
+            arc_resolution,
+            join_type,
+            end_cap.into(),
+            end_cap.into(),
+        )
     }
 }
 
 impl<F: CoordFloat + FloatConst> Offset<F> for geo_types::Line<F> {
-    fn offset_with_arc_resolution(
+    fn offset_with_options(
         &self,
         distance: F,
         arc_resolution: ArcResolution<F>,
+        join_type: JoinType<F>,
+        _end_cap: EndCap<F>,
     ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
-        if distance < F::zero() {
-            return Ok(geo_types::MultiPolygon(Vec::new()));
+        offset_path(
+            &[self.start, self.end],
+            false,
+            distance,
+            arc_resolution,
+            join_type,
+            join_type,
+            join_type,
+        )
+    }
+}
+
+/// Builds the buffer polygon for an ordered path (the coordinates of a `LineString`, or the
+/// two endpoints of a bare `Line`). `closed` treats the path as a ring, wrapping the last
+/// segment's join back around to the first instead of terminating with `start_cap`/`end_cap`.
+///
+/// Both sides of the path are offset independently (mirroring `OffsetCurve::offset_curve`'s
+/// own single-sided construction) so that every interior joint sees the *true* adjacent
+/// segment's direction when building its corner — unlike offsetting each segment as an
+/// isolated capsule and unioning the results, which only ever sees a segment's own reverse
+/// and silently turns `Miter`/`Bevel`/`Square` into a flat perpendicular cut.
+fn offset_path<F: CoordFloat + FloatConst>(
+    vertices: &[geo_types::Coord<F>],
+    closed: bool,
+    distance: F,
+    arc_resolution: ArcResolution<F>,
+    join_type: JoinType<F>,
+    start_cap: JoinType<F>,
+    end_cap: JoinType<F>,
+) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
+    if distance < F::zero() {
+        return Ok(geo_types::MultiPolygon(Vec::new()));
+    }
+
+    let segments = path_segments(vertices);
+    let first_segment = match segments.first() {
+        Some(segment) => segment,
+        None => {
+            return match vertices.first() {
+                Some(vertex) => geo_types::Point::from(*vertex)
+                    .offset_with_options(distance, arc_resolution, join_type, EndCap::default()),
+                None => Ok(geo_types::MultiPolygon(Vec::new())),
+            };
         }
+    };
 
-        let v1 = &self.start;
-        let v2 = &self.end;
-        let e1 = Edge::new(v1, v2);
-
-        if let (Ok(in_normal), Ok(out_normal)) = (e1.inwards_normal(), e1.outwards_normal()) {
-            let offsets = [
-                e1.with_offset(in_normal.x * distance, in_normal.y * distance),
-                e1.inverse_with_offset(out_normal.x * distance, out_normal.y * distance),
-            ];
-
-            let len = 2;
-            let mut vertices = Vec::new();
-
-            for i in 0..len {
-                let current_edge = offsets.get(i).unwrap();
-                let prev_edge = offsets.get((i + len + 1) % len).unwrap();
-                create_arc(
-                    &mut vertices,
-                    if i == 0 { v1 } else { v2 },
-                    distance,
-                    &prev_edge.next,
-                    &current_edge.current,
-                    arc_resolution,
-                    true,
-                );
-            }
+    let side_a = offset_side(&segments, closed, distance, arc_resolution, join_type);
+    let side_b = offset_side(&segments, closed, -distance, arc_resolution, join_type);
 
-            Ok(geo_types::MultiPolygon(vec![geo_types::Polygon::new(
-                geo_types::LineString(vertices),
-                vec![],
-            )]))
+    if closed {
+        // The two sides are each a full ring around the path; the band between them (an
+        // annulus, not their union) is the buffer, so the larger ring becomes the exterior
+        // and the smaller becomes its hole.
+        let (exterior, interior) = if ring_area(&side_a) >= ring_area(&side_b) {
+            (side_a, side_b)
         } else {
-            geo_types::Point::from(self.start).offset_with_arc_resolution(distance, arc_resolution)
-        }
+            (side_b, side_a)
+        };
+        return Ok(union_all(vec![geo_types::Polygon::new(
+            geo_types::LineString(exterior),
+            vec![geo_types::LineString(interior)],
+        )]));
     }
+
+    let last_segment_direction = segments.last().unwrap().direction;
+    let first_segment_direction = first_segment.direction;
+    let negate = |direction: geo_types::Coord<F>| geo_types::Coord::from((-direction.x, -direction.y));
+
+    let a_last = *side_a.last().unwrap();
+    let b_last = *side_b.last().unwrap();
+    let a_first = side_a[0];
+
+    let mut ring = side_a;
+    create_corner(
+        &mut ring,
+        vertices.last().unwrap(),
+        distance.abs(),
+        &a_last,
+        &b_last,
+        Some(last_segment_direction),
+        Some(negate(last_segment_direction)),
+        arc_resolution,
+        end_cap,
+        true,
+    );
+
+    let mut side_b_reversed = side_b;
+    side_b_reversed.reverse();
+    let b_first = side_b_reversed[side_b_reversed.len() - 1]; // the first vertex of the un-reversed side B
+    ring.extend_from_slice(&side_b_reversed[1..]);
+
+    create_corner(
+        &mut ring,
+        &vertices[0],
+        distance.abs(),
+        &b_first,
+        &a_first,
+        Some(negate(first_segment_direction)),
+        Some(first_segment_direction),
+        arc_resolution,
+        start_cap,
+        true,
+    );
+
+    let polygons = vec![geo_types::Polygon::new(geo_types::LineString(ring), vec![])];
+    let unioned = union_all(polygons);
+    Ok(unioned.0.iter().skip(1).fold(
+        geo_types::MultiPolygon::<F>(
+            unioned
+                .0
+                .get(0)
+                .map(|polygon| vec![polygon.clone()])
+                .unwrap_or_default(),
+        ),
+        |result, hole| result.difference(hole, F::clipper_factor()),
+    ))
+}
+
+/// A single valid (non-degenerate) segment of a path, with its normalized tangent direction.
+struct PathSegment<F: CoordFloat + FloatConst> {
+    start: geo_types::Coord<F>,
+    end: geo_types::Coord<F>,
+    direction: geo_types::Coord<F>,
+}
+
+/// Collects the non-degenerate segments of an ordered path, dropping any zero-length segment
+/// (it has no direction to offset along).
+fn path_segments<F: CoordFloat + FloatConst>(
+    vertices: &[geo_types::Coord<F>],
+) -> Vec<PathSegment<F>> {
+    vertices
+        .windows(2)
+        .filter_map(|window| {
+            let dx = window[1].x - window[0].x;
+            let dy = window[1].y - window[0].y;
+            vector_direction(dx, dy).map(|direction| PathSegment {
+                start: window[0],
+                end: window[1],
+                direction,
+            })
+        })
+        .collect()
+}
+
+/// Shifts every segment sideways by `distance` along its normal and joins the shifted
+/// segments at each interior vertex using `join_type`, exactly as `OffsetCurve::offset_curve`
+/// does for a single side. `closed` additionally joins the last segment back to the first
+/// instead of leaving two loose ends.
+fn offset_side<F: CoordFloat + FloatConst>(
+    segments: &[PathSegment<F>],
+    closed: bool,
+    distance: F,
+    arc_resolution: ArcResolution<F>,
+    join_type: JoinType<F>,
+) -> Vec<geo_types::Coord<F>> {
+    let shifted = |point: geo_types::Coord<F>, direction: geo_types::Coord<F>| {
+        let normal = geo_types::Coord::from((-direction.y * distance, direction.x * distance));
+        geo_types::Coord::from((point.x + normal.x, point.y + normal.y))
+    };
+
+    let starts: Vec<_> = segments.iter().map(|s| shifted(s.start, s.direction)).collect();
+    let ends: Vec<_> = segments.iter().map(|s| shifted(s.end, s.direction)).collect();
+
+    let n = segments.len();
+    let mut chain = vec![starts[0]];
+    for i in 1..n {
+        create_corner(
+            &mut chain,
+            &segments[i].start,
+            distance.abs(),
+            &ends[i - 1],
+            &starts[i],
+            Some(segments[i - 1].direction),
+            Some(segments[i].direction),
+            arc_resolution,
+            join_type,
+            is_convex_side(segments[i - 1].direction, segments[i].direction, distance),
+        );
+    }
+    if closed {
+        create_corner(
+            &mut chain,
+            &segments[0].start,
+            distance.abs(),
+            &ends[n - 1],
+            &starts[0],
+            Some(segments[n - 1].direction),
+            Some(segments[0].direction),
+            arc_resolution,
+            join_type,
+            is_convex_side(segments[n - 1].direction, segments[0].direction, distance),
+        );
+    } else {
+        chain.push(ends[n - 1]);
+    }
+    chain
+}
+
+/// The (unsigned, un-halved) shoelace sum for a closed ring. Only used to compare the
+/// relative size of two offset rings around a closed path, so the missing `/ 2` is immaterial.
+fn ring_area<F: CoordFloat + FloatConst>(ring: &[geo_types::Coord<F>]) -> F {
+    let mut area = F::zero();
+    for window in ring.windows(2) {
+        area = area + (window[0].x * window[1].y - window[1].x * window[0].y);
+    }
+    area.abs()
 }
 
 impl<F: CoordFloat + FloatConst> Offset<F> for geo_types::MultiPoint<F> {
-    fn offset_with_arc_resolution(
+    fn offset_with_options(
         &self,
         distance: F,
         arc_resolution: ArcResolution<F>,
+        join_type: JoinType<F>,
+        end_cap: EndCap<F>,
     ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
         if distance < F::zero() {
             return Ok(geo_types::MultiPolygon(Vec::new()));
         }
 
-        let mut multi_point_with_offset = geo_types::MultiPolygon::<F>(Vec::new());
+        let mut polygons = Vec::new();
         for point in self.0.iter() {
-            let point_with_offset = point.offset_with_arc_resolution(distance, arc_resolution)?;
-            multi_point_with_offset =
-                multi_point_with_offset.union(&point_with_offset, F::clipper_factor());
+            let point_with_offset =
+                point.offset_with_options(distance, arc_resolution, join_type, end_cap)?;
+            polygons.extend(point_with_offset.0);
         }
-        Ok(multi_point_with_offset)
+        Ok(union_all(polygons))
     }
 }
 
 impl<F: CoordFloat + FloatConst> Offset<F> for geo_types::Point<F> {
-    fn offset_with_arc_resolution(
+    fn offset_with_options(
         &self,
         distance: F,
         arc_resolution: ArcResolution<F>,
+        _join_type: JoinType<F>,
+        _end_cap: EndCap<F>,
     ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
         if distance < F::zero() {
             return Ok(geo_types::MultiPolygon(Vec::new()));
@@ -290,6 +578,9 @@ impl<F: CoordFloat + FloatConst> Offset<F> for geo_types::Point<F> {
                 let circumference = F::TAU() * distance;
                 (circumference / segment_length).to_usize().unwrap()
             },
+            ArcResolution::MaxDeviation(max_deviation) => {
+                segment_count_for_max_deviation(distance, F::TAU(), max_deviation, 3)
+            },
         };
         let segment_count = segment_count.max(3); // A circle should have at least three sides :)
 
@@ -313,6 +604,403 @@ impl<F: CoordFloat + FloatConst> Offset<F> for geo_types::Point<F> {
     }
 }
 
+/// Offsets a `LineString`/`MultiLineString` by a different distance at each vertex,
+/// linearly interpolating the buffer width between consecutive vertices. Useful for
+/// modelling a strip whose width changes along its length, such as a tapering road.
+pub trait OffsetVariable<F: CoordFloat + FloatConst> {
+    fn offset_variable(
+        &self,
+        distances: &[F],
+        arc_resolution: ArcResolution<F>,
+    ) -> Result<geo_types::MultiPolygon<F>, OffsetError>;
+}
+
+impl<F: CoordFloat + FloatConst> OffsetVariable<F> for geo_types::LineString<F> {
+    fn offset_variable(
+        &self,
+        distances: &[F],
+        arc_resolution: ArcResolution<F>,
+    ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
+        if distances.len() != self.0.len() {
+            return Err(OffsetError::DistanceCountMismatch);
+        }
+
+        let mut polygons = Vec::new();
+        for (index, line) in self.lines().enumerate() {
+            let segment_with_offset = offset_segment_variable(
+                &line.start,
+                &line.end,
+                distances[index],
+                distances[index + 1],
+                arc_resolution,
+            )?;
+            polygons.extend(segment_with_offset.0);
+        }
+        Ok(union_all(polygons))
+    }
+}
+
+impl<F: CoordFloat + FloatConst> OffsetVariable<F> for geo_types::MultiLineString<F> {
+    fn offset_variable(
+        &self,
+        distances: &[F],
+        arc_resolution: ArcResolution<F>,
+    ) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
+        let mut polygons = Vec::new();
+        let mut consumed = 0;
+
+        for line_string in self.0.iter() {
+            let vertex_count = line_string.0.len();
+            let line_string_distances = distances
+                .get(consumed..consumed + vertex_count)
+                .ok_or(OffsetError::DistanceCountMismatch)?;
+            let line_string_with_offset =
+                line_string.offset_variable(line_string_distances, arc_resolution)?;
+            polygons.extend(line_string_with_offset.0);
+            consumed += vertex_count;
+        }
+
+        if consumed != distances.len() {
+            return Err(OffsetError::DistanceCountMismatch);
+        }
+
+        Ok(union_all(polygons))
+    }
+}
+
+/// Builds the tapered buffer quad for a single segment, using `start_distance` at `v1` and
+/// `end_distance` at `v2`. Each endpoint's normal offset is scaled by its own distance
+/// instead of a shared one, and each end cap arc uses the local distance as its radius.
+fn offset_segment_variable<F: CoordFloat + FloatConst>(
+    v1: &geo_types::Coord<F>,
+    v2: &geo_types::Coord<F>,
+    start_distance: F,
+    end_distance: F,
+    arc_resolution: ArcResolution<F>,
+) -> Result<geo_types::MultiPolygon<F>, OffsetError> {
+    if start_distance < F::zero() || end_distance < F::zero() {
+        return Ok(geo_types::MultiPolygon(Vec::new()));
+    }
+
+    let e1 = Edge::new(v1, v2);
+
+    if let (Ok(in_normal), Ok(out_normal)) = (e1.inwards_normal(), e1.outwards_normal()) {
+        let p1_in = geo_types::Coord::from((
+            v1.x + in_normal.x * start_distance,
+            v1.y + in_normal.y * start_distance,
+        ));
+        let p2_in = geo_types::Coord::from((
+            v2.x + in_normal.x * end_distance,
+            v2.y + in_normal.y * end_distance,
+        ));
+        let p1_out = geo_types::Coord::from((
+            v1.x + out_normal.x * start_distance,
+            v1.y + out_normal.y * start_distance,
+        ));
+        let p2_out = geo_types::Coord::from((
+            v2.x + out_normal.x * end_distance,
+            v2.y + out_normal.y * end_distance,
+        ));
+
+        let mut vertices = Vec::new();
+        create_arc(
+            &mut vertices,
+            v1,
+            start_distance,
+            &p1_out,
+            &p1_in,
+            arc_resolution,
+            true,
+        );
+        create_arc(
+            &mut vertices,
+            v2,
+            end_distance,
+            &p2_in,
+            &p2_out,
+            arc_resolution,
+            true,
+        );
+
+        Ok(geo_types::MultiPolygon(vec![geo_types::Polygon::new(
+            geo_types::LineString(vertices),
+            vec![],
+        )]))
+    } else {
+        geo_types::Point::from(*v1).offset_with_options(
+            start_distance.max(end_distance),
+            arc_resolution,
+            JoinType::Round,
+            EndCap::Round,
+        )
+    }
+}
+
+/// Offsets an open `LineString` to one side only, returning the shifted polyline rather
+/// than a closed buffer `MultiPolygon`. The sign of `distance` picks the side.
+///
+/// A zero-length segment has no normal to offset along, so it is dropped before joining.
+/// Every interior vertex is otherwise joined via `create_corner`, which shares its convex/
+/// concave handling with the `Offset` trait's own joins (see `is_convex_side`): a concave
+/// turn is trimmed to the true intersection of the two offset edges instead of being joined,
+/// so the shifted curve stays simple even at a sharp inward turn.
+pub trait OffsetCurve<F: CoordFloat + FloatConst> {
+    fn offset_curve(
+        &self,
+        distance: F,
+        join: JoinType<F>,
+    ) -> Result<geo_types::MultiLineString<F>, OffsetError>;
+}
+
+/// A single segment of a `LineString`, shifted sideways by `distance` along its normal.
+/// `direction` is the segment's own unit direction (before shifting, which doesn't affect
+/// it) and `orig_end` is the un-shifted vertex the segment's `end` corresponds to, used as
+/// the join center with the following segment.
+struct CurveSegment<F: CoordFloat + FloatConst> {
+    start: geo_types::Coord<F>,
+    end: geo_types::Coord<F>,
+    direction: geo_types::Coord<F>,
+    orig_end: geo_types::Coord<F>,
+}
+
+impl<F: CoordFloat + FloatConst> OffsetCurve<F> for geo_types::LineString<F> {
+    fn offset_curve(
+        &self,
+        distance: F,
+        join: JoinType<F>,
+    ) -> Result<geo_types::MultiLineString<F>, OffsetError> {
+        let mut segments = Vec::new();
+        for line in self.lines() {
+            let dx = line.end.x - line.start.x;
+            let dy = line.end.y - line.start.y;
+            // A zero-length segment has no normal to offset along, so it collapses; drop it.
+            if let Some(direction) = vector_direction(dx, dy) {
+                // Rotating the direction by 90° gives the `(-dy, dx)` normal; the sign of
+                // `distance` picks which side of the line it points to.
+                let normal =
+                    geo_types::Coord::from((-direction.y * distance, direction.x * distance));
+                segments.push(CurveSegment {
+                    start: geo_types::Coord::from((
+                        line.start.x + normal.x,
+                        line.start.y + normal.y,
+                    )),
+                    end: geo_types::Coord::from((line.end.x + normal.x, line.end.y + normal.y)),
+                    direction,
+                    orig_end: line.end,
+                });
+            }
+        }
+
+        if segments.is_empty() {
+            return Ok(geo_types::MultiLineString(Vec::new()));
+        }
+
+        let arc_resolution = ArcResolution::default();
+        let radius = distance.abs();
+        let mut vertices = vec![segments[0].start];
+
+        for window in segments.windows(2) {
+            let (current, next) = (&window[0], &window[1]);
+            create_corner(
+                &mut vertices,
+                &current.orig_end,
+                radius,
+                &current.end,
+                &next.start,
+                Some(current.direction),
+                Some(next.direction),
+                arc_resolution,
+                join,
+                is_convex_side(current.direction, next.direction, distance),
+            );
+        }
+
+        vertices.push(segments.last().unwrap().end);
+
+        Ok(geo_types::MultiLineString(vec![geo_types::LineString(
+            vertices,
+        )]))
+    }
+}
+
+/// Whether the turn from `prev_direction` to `current_direction` is convex on the side being
+/// built, where `distance` is the signed offset that picked that side (positive rotates the
+/// segment's own direction +90° to get the normal, i.e. offsets to its left; negative to its
+/// right). A left turn (`prev_direction` rotated towards `current_direction` counterclockwise,
+/// positive cross product) opens a gap on the right side and pinches the left side, and vice
+/// versa for a right turn — so the corner is convex for this side exactly when the turn's
+/// cross product and the offset's sign disagree.
+fn is_convex_side<F: CoordFloat + FloatConst>(
+    prev_direction: geo_types::Coord<F>,
+    current_direction: geo_types::Coord<F>,
+    distance: F,
+) -> bool {
+    let turn = prev_direction.x * current_direction.y - prev_direction.y * current_direction.x;
+    turn * distance <= F::zero()
+}
+
+/// Connects `start_vertex` (the end of the previous edge) and `end_vertex` (the start of
+/// the current edge) at the corner centered on `center`. `prev_direction`/`current_direction`
+/// are the unit directions of the two edges (`None` for a degenerate, zero-length edge).
+///
+/// `convex` says whether this corner is convex for the side being built (see
+/// `is_convex_side`): a convex corner leaves a gap between the two offset edges, filled using
+/// the style requested by `join_type` (`Round` falls back to `create_arc`; the others push
+/// straight-line approximations). A concave (reflex) corner instead makes the two offset
+/// edges cross, so `join_type` is ignored and the boundary is trimmed straight to their true
+/// intersection point (or `center`, if they're parallel) to avoid a self-crossing result.
+#[allow(clippy::too_many_arguments)]
+fn create_corner<F: CoordFloat + FloatConst>(
+    vertices: &mut Vec<geo_types::Coord<F>>,
+    center: &geo_types::Coord<F>,
+    distance: F,
+    start_vertex: &geo_types::Coord<F>,
+    end_vertex: &geo_types::Coord<F>,
+    prev_direction: Option<geo_types::Coord<F>>,
+    current_direction: Option<geo_types::Coord<F>>,
+    arc_resolution: ArcResolution<F>,
+    join_type: JoinType<F>,
+    convex: bool,
+) {
+    if !convex {
+        let trim_point = match (prev_direction, current_direction) {
+            (Some(d1), Some(d2)) => {
+                line_intersection(*start_vertex, d1, *end_vertex, d2).unwrap_or(*center)
+            }
+            _ => *center,
+        };
+        vertices.push(trim_point);
+        return;
+    }
+
+    match join_type {
+        JoinType::Round => create_arc(
+            vertices,
+            center,
+            distance,
+            start_vertex,
+            end_vertex,
+            arc_resolution,
+            true,
+        ),
+        JoinType::Bevel => {
+            vertices.push(*start_vertex);
+            vertices.push(*end_vertex);
+        }
+        JoinType::Miter(miter_limit) => {
+            let miter_point = mitered_corner(
+                center,
+                distance,
+                start_vertex,
+                prev_direction,
+                end_vertex,
+                current_direction,
+                miter_limit,
+            );
+            vertices.push(*start_vertex);
+            if let Some(miter_point) = miter_point {
+                vertices.push(miter_point);
+            }
+            vertices.push(*end_vertex);
+        }
+        JoinType::Square => {
+            vertices.push(*start_vertex);
+            if let (Some(prev_direction), Some(current_direction)) =
+                (prev_direction, current_direction)
+            {
+                vertices.push(geo_types::Coord::from((
+                    start_vertex.x + prev_direction.x * distance,
+                    start_vertex.y + prev_direction.y * distance,
+                )));
+                vertices.push(geo_types::Coord::from((
+                    end_vertex.x - current_direction.x * distance,
+                    end_vertex.y - current_direction.y * distance,
+                )));
+            }
+            vertices.push(*end_vertex);
+        }
+    }
+}
+
+/// Normalizes a direction vector, returning `None` when it has zero length.
+fn vector_direction<F: CoordFloat + FloatConst>(dx: F, dy: F) -> Option<geo_types::Coord<F>> {
+    let length = (dx * dx + dy * dy).sqrt();
+    if length.is_zero() {
+        None
+    } else {
+        Some(geo_types::Coord::from((dx / length, dy / length)))
+    }
+}
+
+/// Intersects the infinite lines through `start_vertex` (direction `prev_direction`) and
+/// `end_vertex` (direction `current_direction`), returning the miter point as long as its
+/// miter length (the distance from `center` to the intersection, divided by `distance`)
+/// does not exceed `miter_limit`.
+#[allow(clippy::too_many_arguments)]
+fn mitered_corner<F: CoordFloat + FloatConst>(
+    center: &geo_types::Coord<F>,
+    distance: F,
+    start_vertex: &geo_types::Coord<F>,
+    prev_direction: Option<geo_types::Coord<F>>,
+    end_vertex: &geo_types::Coord<F>,
+    current_direction: Option<geo_types::Coord<F>>,
+    miter_limit: F,
+) -> Option<geo_types::Coord<F>> {
+    let d1 = prev_direction?;
+    let d2 = current_direction?;
+
+    let intersection = line_intersection(*start_vertex, d1, *end_vertex, d2)?;
+
+    let dx = intersection.x - center.x;
+    let dy = intersection.y - center.y;
+    let miter_length = (dx * dx + dy * dy).sqrt() / distance;
+
+    if miter_length <= miter_limit {
+        Some(intersection)
+    } else {
+        None
+    }
+}
+
+/// Intersects the infinite line through `p1` with direction `d1` and the infinite line
+/// through `p2` with direction `d2`. Returns `None` when the lines are parallel.
+fn line_intersection<F: CoordFloat + FloatConst>(
+    p1: geo_types::Coord<F>,
+    d1: geo_types::Coord<F>,
+    p2: geo_types::Coord<F>,
+    d2: geo_types::Coord<F>,
+) -> Option<geo_types::Coord<F>> {
+    let denominator = d1.x * d2.y - d1.y * d2.x;
+    if denominator.is_zero() {
+        return None;
+    }
+
+    let diff = geo_types::Coord::from((p2.x - p1.x, p2.y - p1.y));
+    let t = (diff.x * d2.y - diff.y * d2.x) / denominator;
+
+    Some(geo_types::Coord::from((p1.x + d1.x * t, p1.y + d1.y * t)))
+}
+
+/// Chooses a segment count for an arc of the given `radius` spanning `angle` radians so
+/// that the sagitta (maximum chord deviation) of each segment stays under `max_deviation`.
+/// For a sub-segment spanning `2 * theta`, the sagitta is `radius * (1 - cos(theta))`, so
+/// solving for the tolerance gives `theta = acos(1 - max_deviation / radius)`.
+fn segment_count_for_max_deviation<F: CoordFloat + FloatConst>(
+    radius: F,
+    angle: F,
+    max_deviation: F,
+    minimum: usize,
+) -> usize {
+    if radius.is_zero() || max_deviation >= radius {
+        return minimum;
+    }
+
+    let theta = (F::one() - max_deviation / radius).acos();
+    let segment_count = (angle / (theta + theta)).ceil().to_usize().unwrap_or(minimum);
+
+    segment_count.max(minimum)
+}
+
 fn create_arc<F: CoordFloat + FloatConst>(
     vertices: &mut Vec<geo_types::Coord<F>>,
     center: &geo_types::Coord<F>,
@@ -348,6 +1036,9 @@ fn create_arc<F: CoordFloat + FloatConst>(
             let arc_length = angle * radius;
             (arc_length / segment_length).to_usize().unwrap()
         },
+        ArcResolution::MaxDeviation(max_deviation) => {
+            segment_count_for_max_deviation(radius, angle, max_deviation, 1)
+        },
     };
 
     let segment_angle =
@@ -363,3 +1054,259 @@ fn create_arc<F: CoordFloat + FloatConst>(
     }
     vertices.push(*end_vertex);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coord(x: f64, y: f64) -> geo_types::Coord<f64> {
+        geo_types::Coord::from((x, y))
+    }
+
+    fn assert_coord_eq(actual: geo_types::Coord<f64>, expected: geo_types::Coord<f64>) {
+        assert!(
+            (actual.x - expected.x).abs() < 1e-9 && (actual.y - expected.y).abs() < 1e-9,
+            "expected {expected:?}, got {actual:?}"
+        );
+    }
+
+    /// The vertices `offset_side` produces at the interior joint of an L-shaped path depend
+    /// entirely on `create_corner` seeing the true directions of both adjacent segments; this
+    /// is what the old per-segment-capsule construction got wrong (see chunk0-1's fix).
+    /// The path turns left, so its convex side is the right one (`distance < 0`).
+    fn l_shape_segments() -> Vec<PathSegment<f64>> {
+        path_segments(&[coord(0.0, 0.0), coord(10.0, 0.0), coord(10.0, 10.0)])
+    }
+
+    #[test]
+    fn offset_side_bevel_cuts_the_corner_flat() {
+        let segments = l_shape_segments();
+        let side = offset_side(&segments, false, -2.0, ArcResolution::default(), JoinType::Bevel);
+        assert_eq!(
+            side,
+            vec![coord(0.0, -2.0), coord(10.0, -2.0), coord(12.0, 0.0), coord(12.0, 10.0)]
+        );
+    }
+
+    #[test]
+    fn offset_side_miter_extends_to_the_true_corner() {
+        let segments = l_shape_segments();
+        let side = offset_side(
+            &segments,
+            false,
+            -2.0,
+            ArcResolution::default(),
+            JoinType::Miter(10.0),
+        );
+        assert_eq!(
+            side,
+            vec![
+                coord(0.0, -2.0),
+                coord(10.0, -2.0),
+                coord(12.0, -2.0),
+                coord(12.0, 0.0),
+                coord(12.0, 10.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn offset_side_miter_falls_back_when_limit_exceeded() {
+        let segments = l_shape_segments();
+        // The true miter point is (12, -2), sqrt(2) times the distance from the vertex (a miter
+        // length of sqrt(2)); a limit below that forces the same flat cut Bevel would produce.
+        let side = offset_side(
+            &segments,
+            false,
+            -2.0,
+            ArcResolution::default(),
+            JoinType::Miter(1.0),
+        );
+        assert_eq!(
+            side,
+            vec![coord(0.0, -2.0), coord(10.0, -2.0), coord(12.0, 0.0), coord(12.0, 10.0)]
+        );
+    }
+
+    #[test]
+    fn offset_side_square_extends_along_each_edge() {
+        let segments = l_shape_segments();
+        let side = offset_side(&segments, false, -2.0, ArcResolution::default(), JoinType::Square);
+        // At a right angle both edge extensions end on the same point, (12, -2).
+        assert_eq!(
+            side,
+            vec![
+                coord(0.0, -2.0),
+                coord(10.0, -2.0),
+                coord(12.0, -2.0),
+                coord(12.0, -2.0),
+                coord(12.0, 0.0),
+                coord(12.0, 10.0),
+            ]
+        );
+    }
+
+    /// A checkmark-shaped "V": two 45° segments meeting at a sharp, downward-pointing vertex.
+    fn vee_segments() -> Vec<PathSegment<f64>> {
+        path_segments(&[coord(0.0, 2.0), coord(2.0, 0.0), coord(4.0, 2.0)])
+    }
+
+    #[test]
+    fn offset_side_concave_corner_trims_to_the_intersection() {
+        // Offsetting to the left (`distance > 0`) pinches this corner instead of opening a
+        // gap, so joining it like a convex corner makes the two offset edges cross (the
+        // previously-reported bowtie). The fix collapses the corner to the true intersection
+        // of the two offset edges instead of pushing both of their endpoints.
+        let side = offset_side(&vee_segments(), false, 1.0, ArcResolution::default(), JoinType::Bevel);
+
+        let h = std::f64::consts::FRAC_1_SQRT_2;
+        assert_eq!(side.len(), 3, "a concave corner must collapse to a single trim point");
+        assert_coord_eq(side[0], coord(h, 2.0 + h));
+        assert_coord_eq(side[1], coord(2.0, 2f64.sqrt()));
+        assert_coord_eq(side[2], coord(4.0 - h, 2.0 + h));
+    }
+
+    #[test]
+    fn offset_side_concave_corner_ignores_join_type() {
+        // A concave corner is trimmed the same way regardless of `join_type`, since there is
+        // no gap for a join style to fill.
+        let side = offset_side(
+            &vee_segments(),
+            false,
+            1.0,
+            ArcResolution::default(),
+            JoinType::Miter(10.0),
+        );
+        assert_eq!(side.len(), 3);
+        assert_coord_eq(side[1], coord(2.0, 2f64.sqrt()));
+    }
+
+    #[test]
+    fn offset_side_convex_side_of_the_same_corner_still_joins() {
+        // The opposite side of the same "V" (`distance < 0`) is convex: the two offset edges
+        // diverge and still need `join_type` to bridge the gap, exactly as before this fix.
+        let side = offset_side(&vee_segments(), false, -1.0, ArcResolution::default(), JoinType::Bevel);
+
+        let h = std::f64::consts::FRAC_1_SQRT_2;
+        assert_eq!(side.len(), 4);
+        assert_coord_eq(side[0], coord(-h, 2.0 - h));
+        assert_coord_eq(side[1], coord(2.0 - h, -h));
+        assert_coord_eq(side[2], coord(2.0 + h, -h));
+        assert_coord_eq(side[3], coord(4.0 + h, 2.0 - h));
+    }
+
+    /// Bounding box of every exterior ring in `polygons`, used below in place of exact vertex
+    /// assertions for anything routed through Clipper (whose integer-scaled union may reorder
+    /// or dedup points, but preserves the extent of the shape within `1 / clipper_factor`).
+    fn bounds(polygons: &geo_types::MultiPolygon<f64>) -> (f64, f64, f64, f64) {
+        let mut min_x = f64::INFINITY;
+        let mut max_x = f64::NEG_INFINITY;
+        let mut min_y = f64::INFINITY;
+        let mut max_y = f64::NEG_INFINITY;
+        for polygon in &polygons.0 {
+            for c in &polygon.exterior().0 {
+                min_x = min_x.min(c.x);
+                max_x = max_x.max(c.x);
+                min_y = min_y.min(c.y);
+                max_y = max_y.max(c.y);
+            }
+        }
+        (min_x, max_x, min_y, max_y)
+    }
+
+    #[test]
+    fn offset_curve_bevel_matches_single_sided_offset() {
+        let line = geo_types::LineString(vec![coord(0.0, 0.0), coord(10.0, 0.0), coord(10.0, 10.0)]);
+        let offset = line.offset_curve(-2.0, JoinType::Bevel).unwrap();
+        assert_eq!(offset.0.len(), 1);
+        assert_eq!(
+            offset.0[0].0,
+            vec![coord(0.0, -2.0), coord(10.0, -2.0), coord(12.0, 0.0), coord(12.0, 10.0)]
+        );
+    }
+
+    #[test]
+    fn offset_curve_trims_a_concave_corner_like_offset_side_does() {
+        // Shares create_corner/is_convex_side with the Offset trait's own joins, so a
+        // concave turn collapses to the true intersection here too instead of crossing.
+        let line = geo_types::LineString(vec![coord(0.0, 2.0), coord(2.0, 0.0), coord(4.0, 2.0)]);
+        let offset = line.offset_curve(1.0, JoinType::Bevel).unwrap();
+        assert_eq!(offset.0.len(), 1);
+        assert_eq!(offset.0[0].0.len(), 3);
+        assert_coord_eq(offset.0[0].0[1], coord(2.0, 2f64.sqrt()));
+    }
+
+    #[test]
+    fn end_cap_square_extends_past_the_line_ends() {
+        let line = geo_types::LineString(vec![coord(0.0, 0.0), coord(10.0, 0.0)]);
+        let buffer = line
+            .offset_with_options(1.0, ArcResolution::default(), JoinType::Bevel, EndCap::Square)
+            .unwrap();
+        let (min_x, max_x, min_y, max_y) = bounds(&buffer);
+        assert!((min_x - -1.0).abs() < 0.01, "min_x was {min_x}");
+        assert!((max_x - 11.0).abs() < 0.01, "max_x was {max_x}");
+        assert!((min_y - -1.0).abs() < 0.01, "min_y was {min_y}");
+        assert!((max_y - 1.0).abs() < 0.01, "max_y was {max_y}");
+    }
+
+    #[test]
+    fn offset_variable_tapers_the_width_along_the_line() {
+        let line = geo_types::LineString(vec![coord(0.0, 0.0), coord(10.0, 0.0)]);
+        let buffer = line
+            .offset_variable(&[1.0, 3.0], ArcResolution::default())
+            .unwrap();
+        let (min_x, max_x, _min_y, max_y) = bounds(&buffer);
+        // The start cap has radius 1, the end cap radius 3, so the buffer extends further
+        // past the wide end and reaches a wider half-width there too. Each cap is a default
+        // 5-segment semicircle, whose outermost vertices lie 18° off the line's axis.
+        let reach = (std::f64::consts::PI / 10.0).cos();
+        assert!((min_x - -reach).abs() < 0.01, "min_x was {min_x}");
+        assert!((max_x - (10.0 + 3.0 * reach)).abs() < 0.01, "max_x was {max_x}");
+        assert!(max_y.abs() > 2.0 && max_y.abs() <= 3.01, "max_y was {max_y}");
+    }
+
+    #[test]
+    fn segment_count_for_max_deviation_solves_the_sagitta_tolerance() {
+        // A 180° arc of radius 10 tolerating a sagitta of 5 per sub-segment: theta = acos(1 -
+        // 5/10) = acos(0.5) = PI/3, so segment_count = ceil(PI / (2 * PI/3)) = ceil(1.5) = 2.
+        let segment_count =
+            segment_count_for_max_deviation(10.0_f64, std::f64::consts::PI, 5.0, 1);
+        assert_eq!(segment_count, 2);
+    }
+
+    #[test]
+    fn segment_count_for_max_deviation_clamps_to_the_minimum_for_zero_radius() {
+        let segment_count = segment_count_for_max_deviation(0.0_f64, std::f64::consts::PI, 0.1, 3);
+        assert_eq!(segment_count, 3);
+    }
+
+    #[test]
+    fn segment_count_for_max_deviation_clamps_to_the_minimum_when_tolerance_exceeds_radius() {
+        let segment_count = segment_count_for_max_deviation(5.0_f64, std::f64::consts::PI, 5.0, 3);
+        assert_eq!(segment_count, 3);
+    }
+
+    #[test]
+    fn union_all_merges_overlapping_polygons_into_one() {
+        let square = |x0: f64, y0: f64, x1: f64, y1: f64| {
+            geo_types::Polygon::new(
+                geo_types::LineString(vec![
+                    coord(x0, y0),
+                    coord(x1, y0),
+                    coord(x1, y1),
+                    coord(x0, y1),
+                    coord(x0, y0),
+                ]),
+                vec![],
+            )
+        };
+        let result = union_all(vec![square(0.0, 0.0, 2.0, 2.0), square(1.0, 1.0, 3.0, 3.0)]);
+
+        assert_eq!(result.0.len(), 1, "overlapping squares should merge into one polygon");
+        let (min_x, max_x, min_y, max_y) = bounds(&result);
+        assert!((min_x - 0.0).abs() < 0.01, "min_x was {min_x}");
+        assert!((max_x - 3.0).abs() < 0.01, "max_x was {max_x}");
+        assert!((min_y - 0.0).abs() < 0.01, "min_y was {min_y}");
+        assert!((max_y - 3.0).abs() < 0.01, "max_y was {max_y}");
+    }
+}